@@ -1,22 +1,19 @@
-use rppal::spi::{Spi, Bus, SlaveSelect, Mode};
-use embedded_hal::blocking::delay::DelayMs;
+use embedded_hal::delay::DelayNs;
 use rppal::hal::Delay;
+use rppal::spi::{Bus, Mode, SlaveSelect, Spi};
 
-use hx711_spi::Hx711;
+use hx711_spi::blocking::Hx711;
 
-fn main()
-{
+fn main() {
     let mut delay = Delay::new();
     let spi = Spi::new(Bus::Spi0, SlaveSelect::Ss0, 1_000_000, Mode::Mode0).unwrap();
-    let mut test = Hx711::new(spi, Delay::new()).unwrap();
-    // test.spi.configure()
+    let mut hx711 = Hx711::new(spi, Delay::new());
 
-	test.reset().unwrap();
+    hx711.reset().unwrap();
 
-	loop
-	{
-        let v = test.readout().unwrap();
-		println!("value = {}", v);
-		delay.delay_ms(1u8);
-	}
+    loop {
+        let v = hx711.read_val().unwrap();
+        println!("value = {}", v);
+        delay.delay_ms(1);
+    }
 }
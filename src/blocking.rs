@@ -0,0 +1,159 @@
+//! Blocking counterpart to the async [`crate::Hx711`], enabled by the `blocking` feature for
+//! users who don't want to pull in an async executor -- e.g. a Raspberry Pi via `rppal`, or a
+//! bare-metal loop. Shares [`decode_output`](crate::decode_output), the bit-pattern constants
+//! and the data-ready poll tuning with the async driver; only the SPI/delay I/O layer differs.
+//!
+//! This module does not yet have an equivalent of the async driver's [`crate::DataReadyPin`],
+//! [`crate::PowerPin`] or channel/gain type-state -- it only covers the plain polling read path.
+//! That parity is deliberately out of scope for now, not an oversight.
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::spi::SpiBus;
+
+use crate::{
+    decode_output, Error, CLOCK, DATA_READY_BACKOFF_US, DATA_READY_POLL_ATTEMPTS, GAIN128, GAIN32,
+    GAIN64, RESET_SIGNAL, SIGNAL_LOW,
+};
+
+/// The HX711 has two channels: `A` for the load cell and `B` for AD conversion of other signals.
+/// Channel `A` supports gains of 128 (default) and 64, `B` has a fixed gain of 32.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Mode {
+    // bits have to be converted for correct transfer 1 -> 10, 0 -> 00
+    /// Convert channel A with a gain factor of 128
+    ChAGain128 = GAIN128,
+    /// Convert channel B with a gain factor of 32
+    ChBGain32 = GAIN32,
+    /// Convert channel A with a gain factor of 64
+    ChAGain64 = GAIN64, // there is a typo in the official datasheet: in Fig.2 it says channel B instead of A
+}
+
+/// Represents an instance of a HX711 device, driven over plain (blocking) `embedded-hal` traits
+/// instead of `embedded-hal-async`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Hx711<SPI, DELAY> {
+    spi: SPI,
+    delay: DELAY,
+    // device specific
+    mode: Mode,
+}
+
+impl<SPI, DELAY> Hx711<SPI, DELAY>
+where
+    DELAY: DelayNs,
+    SPI: SpiBus,
+{
+    /// opens a connection to a HX711 on a specified SPI.
+    ///
+    /// The datasheet specifies PD_SCK high time and PD_SCK low time to be in the 0.2 to 50 us range,
+    /// therefore bus speed has to be between 5 MHz and 20 kHz. 1 MHz seems to be a good choice.
+    /// DELAY is an embedded_hal implementation of DelayNs.
+    pub fn new(spi: SPI, delay: DELAY) -> Self {
+        Hx711 {
+            spi,
+            delay,
+            mode: Mode::ChAGain128,
+        }
+    }
+
+    /// reads a value from the HX711 and returns it
+    /// # Errors
+    /// Returns SPI errors and [`Error::NotReadyInTime`] if data isn't ready in time
+    pub fn read_val(&mut self) -> Result<i32, Error<SPI::Error>> {
+        // When output data is not ready for retrieval, digital output pin DOUT is high.
+        // Serial clock input PD_SCK should be low. When DOUT goes
+        // to low, it indicates data is ready for retrieval.
+        let mut txrx: [u8; 1] = [SIGNAL_LOW];
+
+        let mut ready = false;
+        for _attempt in 0..DATA_READY_POLL_ATTEMPTS {
+            self.spi.transfer_in_place(&mut txrx)?;
+
+            if txrx[0] & 0b01 != 0b01 {
+                ready = true;
+                break;
+            }
+
+            self.delay.delay_us(DATA_READY_BACKOFF_US);
+        }
+
+        if !ready {
+            return Err(Error::NotReadyInTime);
+        }
+
+        let mut buffer: [u8; 7] = [CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, self.mode as u8];
+
+        self.spi.transfer_in_place(&mut buffer)?;
+
+        Ok(decode_output(&buffer)) // value should be in range 0x800000 - 0x7fffff according to datasheet
+    }
+
+    /// Reset the chip to it's default state. Mode is set to convert channel A with a gain factor of 128.
+    /// # Errors
+    /// Returns SPI errors
+    #[inline]
+    pub fn reset(&mut self) -> Result<(), SPI::Error> {
+        // when PD_SCK pin changes from low to high and stays at high for longer than 60µs,
+        // HX711 enters power down mode.
+        // When PD_SCK returns to low, chip will reset and enter normal operation mode.
+        // speed is the raw SPI speed -> half bits per second.
+
+        // max SPI clock frequency should be 5 MHz to satisfy the 0.2 us limit for the pulse length
+        // we have to output more than 300 bytes to keep the line for at least 60 us high.
+
+        let mut buffer: [u8; 301] = RESET_SIGNAL;
+
+        self.spi.transfer_in_place(&mut buffer)?;
+        self.mode = Mode::ChAGain128; // this is the default mode after reset
+
+        Ok(())
+    }
+
+    /// Set the mode to the value specified.
+    /// # Errors
+    /// Returns SPI errors
+    #[inline]
+    pub fn set_mode(&mut self, m: Mode) -> Result<Mode, Error<SPI::Error>> {
+        self.mode = m;
+        self.read_val()?; // read writes Mode for the next read()
+        Ok(m)
+    }
+
+    #[inline]
+    /// Get the current mode.
+    pub fn mode(&mut self) -> Mode {
+        self.mode
+    }
+
+    /// Reads `n` consecutive samples (see [`crate::MAX_FILTER_SAMPLES`] for the cap on `n`) and
+    /// returns their arithmetic mean, to even out noise on a load-cell reading.
+    /// # Errors
+    /// Returns SPI errors and [`Error::NotReadyInTime`], same as [`read_val`](Self::read_val).
+    pub fn read_averaged(&mut self, n: u16) -> Result<i32, Error<SPI::Error>> {
+        let n = crate::clamp_sample_count(n);
+
+        let mut samples = [0i32; crate::MAX_FILTER_SAMPLES];
+        for sample in samples.iter_mut().take(n) {
+            *sample = self.read_val()?;
+        }
+
+        Ok(crate::average(&samples[..n]))
+    }
+
+    /// Reads `n` consecutive samples (see [`crate::MAX_FILTER_SAMPLES`] for the cap on `n`) and
+    /// returns their median, to reject the occasional outlier spike common on load-cell setups.
+    /// # Errors
+    /// Returns SPI errors and [`Error::NotReadyInTime`], same as [`read_val`](Self::read_val).
+    pub fn read_median(&mut self, n: u16) -> Result<i32, Error<SPI::Error>> {
+        let n = crate::clamp_sample_count(n);
+
+        let mut samples = [0i32; crate::MAX_FILTER_SAMPLES];
+        for sample in samples.iter_mut().take(n) {
+            *sample = self.read_val()?;
+        }
+
+        Ok(crate::median(&mut samples[..n]))
+    }
+}
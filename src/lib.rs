@@ -1,12 +1,50 @@
 #![doc = include_str!("../README.md")]
 #![forbid(unsafe_code)]
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 use bitmatch::bitmatch;
+use core::marker::PhantomData;
 use core::unimplemented;
+use embedded_hal::digital::InputPin;
+use embedded_hal::digital::OutputPin;
 use embedded_hal_async::delay;
 use embedded_hal_async::spi;
 
+/// Blocking counterpart to this crate's async [`Hx711`], for users on plain `embedded-hal`.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+// Number of DATA_READY_BACKOFF_US-spaced polls the default (no EOC pin) data-ready wait makes
+// before giving up with `Error::NotReadyInTime`.
+const DATA_READY_POLL_ATTEMPTS: u16 = 1000;
+// Backoff between data-ready polls, for both the default SPI polling and the EOC pin wait.
+const DATA_READY_BACKOFF_US: u32 = 10;
+
+/// Upper bound on `n` accepted by [`Hx711::read_averaged`] and [`Hx711::read_median`]: both
+/// buffer their samples on the stack (this crate is `no_std` and alloc-free), so a larger `n`
+/// is silently capped to this many samples rather than growing the buffer.
+pub const MAX_FILTER_SAMPLES: usize = 32;
+
+/// Clamps a caller-supplied sample count to the `[1, MAX_FILTER_SAMPLES]` range accepted by
+/// [`Hx711::read_averaged`]/[`Hx711::read_median`] (and their `blocking` counterparts): `0` is
+/// rounded up to `1` sample rather than returning a read with no samples taken, and anything
+/// over [`MAX_FILTER_SAMPLES`] is capped rather than growing the on-stack buffer.
+pub(crate) fn clamp_sample_count(n: u16) -> usize {
+    (n as usize).clamp(1, MAX_FILTER_SAMPLES)
+}
+
+/// Arithmetic mean of `samples`, widening to `i64` so the sum can't overflow `i32`.
+pub(crate) fn average(samples: &[i32]) -> i32 {
+    let sum: i64 = samples.iter().copied().map(i64::from).sum();
+    (sum / samples.len() as i64) as i32
+}
+
+/// Median of `samples`: sorts in place and returns the middle element.
+pub(crate) fn median(samples: &mut [i32]) -> i32 {
+    samples.sort_unstable();
+    samples[samples.len() / 2]
+}
+
 // Bit pattern definitions for the communication with the hx711. All have to be bitwise negate
 // for the ```invert-sdo``` feature
 
@@ -49,7 +87,15 @@ const RESET_SIGNAL: [u8; 301] = [0x00; 301];
 
 /// The HX711 has two channels: `A` for the load cell and `B` for AD conversion of other signals.
 /// Channel `A` supports gains of 128 (default) and 64, `B` has a fixed gain of 32.
-#[derive(Copy, Clone, defmt::Format)]
+///
+/// This runtime representation of the mode is only used by the `runtime-mode` fallback API.
+/// Prefer the compile-time tagged [`Hx711<SPI, DELAY, MODE>`] and its `into_*` conversions,
+/// which make it impossible to call `read_val` on a driver that isn't known, at compile time,
+/// to be in the mode you expect. Note that the `i32` returned by `read_val` is untagged, so
+/// this guarantee is about the driver you read from, not about a value once it's been read out.
+#[cfg(feature = "runtime-mode")]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Mode {
     // bits have to be converted for correct transfer 1 -> 10, 0 -> 00
@@ -61,26 +107,247 @@ pub enum Mode {
     ChAGain64 = GAIN64, // there is a typo in the official datasheet: in Fig.2 it says channel B instead of A
 }
 
-#[derive(defmt::Format)]
-pub enum Error<E: defmt::Format> {
+/// Zero-sized marker type tagging a [`Hx711`] that is configured to convert channel A with a
+/// gain factor of 128. This is the mode the chip is in after `new`/`reset`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ChAGain128;
+
+/// Zero-sized marker type tagging a [`Hx711`] that is configured to convert channel B with a
+/// gain factor of 32.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ChBGain32;
+
+/// Zero-sized marker type tagging a [`Hx711`] that is configured to convert channel A with a
+/// gain factor of 64.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ChAGain64;
+
+/// Gives access to the bit pattern a mode marker type stands for. Implemented for
+/// [`ChAGain128`], [`ChBGain32`] and [`ChAGain64`], the markers used to tag [`Hx711`].
+///
+/// `pub` (rather than private) so it can appear as a bound on public `Hx711` impls; there is
+/// no supported reason to implement it for your own types.
+pub trait ModeBits {
+    #[doc(hidden)]
+    const BITS: u8;
+}
+
+impl ModeBits for ChAGain128 {
+    const BITS: u8 = GAIN128;
+}
+
+impl ModeBits for ChBGain32 {
+    const BITS: u8 = GAIN32;
+}
+
+impl ModeBits for ChAGain64 {
+    const BITS: u8 = GAIN64;
+}
+
+/// Seals [`DataReady`] and [`PowerControl`] against implementations outside this crate. Both use
+/// `async fn`, which would otherwise trip `#[warn(async_fn_in_trait)]`'s concerns about
+/// downstream implementors (the returned future is unnameable, and gets no auto-trait bounds);
+/// since only `()` and this crate's own wrapper types ever implement them, those concerns don't
+/// apply, so both traits carry `#[allow(async_fn_in_trait)]` instead of desugaring to `-> impl
+/// Future`.
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for () {}
+
+/// Strategy used to wait for a conversion to be ready before clocking it out: either polling
+/// DOUT over SPI, the default (see [`Hx711::new`]), or watching a dedicated EOC/DOUT
+/// [`InputPin`] (see [`Hx711::new_with_data_ready`]), which avoids spending a SPI transaction on
+/// every poll and allows interrupt-friendly, lower-power waiting.
+///
+/// `pub` (rather than private) so it can appear as a bound on public `Hx711` impls; sealed (see
+/// [`sealed`]) so there is no way to implement it for your own types.
+#[allow(async_fn_in_trait)]
+pub trait DataReady<SPI, DELAY>: sealed::Sealed
+where
+    SPI: spi::SpiBus,
+    DELAY: delay::DelayNs,
+{
+    async fn wait_ready(
+        &mut self,
+        spi: &mut SPI,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<SPI::Error>>;
+}
+
+impl<SPI, DELAY> DataReady<SPI, DELAY> for ()
+where
+    SPI: spi::SpiBus,
+    DELAY: delay::DelayNs,
+{
+    async fn wait_ready(
+        &mut self,
+        spi: &mut SPI,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<SPI::Error>> {
+        // When output data is not ready for retrieval, digital output pin DOUT is high.
+        // Serial clock input PD_SCK should be low. When DOUT goes
+        // to low, it indicates data is ready for retrieval.
+        let mut txrx: [u8; 1] = [SIGNAL_LOW];
+
+        for _attempt in 0..DATA_READY_POLL_ATTEMPTS {
+            spi.transfer_in_place(&mut txrx).await?;
+            if txrx[0] & 0b01 != 0b01 {
+                return Ok(());
+            }
+            delay.delay_us(DATA_READY_BACKOFF_US).await;
+        }
+
+        Err(Error::NotReadyInTime)
+    }
+}
+
+/// Wraps a dedicated EOC/DOUT [`InputPin`] together with how many backoff ticks to wait before
+/// giving up. Produced by [`Hx711::new_with_data_ready`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DataReadyPin<P> {
+    pin: P,
+    timeout_attempts: u16,
+}
+
+impl<P> sealed::Sealed for DataReadyPin<P> {}
+
+impl<SPI, DELAY, P> DataReady<SPI, DELAY> for DataReadyPin<P>
+where
+    SPI: spi::SpiBus,
+    DELAY: delay::DelayNs,
+    P: InputPin,
+{
+    async fn wait_ready(
+        &mut self,
+        _spi: &mut SPI,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<SPI::Error>> {
+        for _attempt in 0..self.timeout_attempts {
+            // an error reading the pin is treated the same as "not ready yet"; it will
+            // eventually surface as `NotReadyInTime` if it never recovers.
+            if self.pin.is_low().unwrap_or(false) {
+                return Ok(());
+            }
+            delay.delay_us(DATA_READY_BACKOFF_US).await;
+        }
+
+        Err(Error::NotReadyInTime)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E> {
     Spi(E),
     /// Device took to long to report ready
     NotReadyInTime,
 }
 
-impl<E: defmt::Format> From<E> for Error<E> {
+impl<E> From<E> for Error<E> {
     fn from(error: E) -> Self {
         Self::Spi(error)
     }
 }
 
-/// Represents an instance of a HX711 device
-#[derive(defmt::Format)]
-pub struct Hx711<SPI, DELAY> {
+/// Strategy used by [`Hx711::disable`]/[`Hx711::enable`] to actually power the chip down/up via
+/// its PD_SCK line: `()` (the default) has no pin wired up, so both stay unimplemented, while
+/// [`PowerPin`] (see [`Hx711::new_with_power_pin`]) drives a real GPIO output tied to PD_SCK.
+///
+/// `pub` (rather than private) so it can appear as a bound on public `Hx711` impls, and so
+/// `PWR::Error` can appear in the public signatures of [`Hx711::disable`]/[`Hx711::enable`];
+/// sealed (see [`sealed`]) so there is no way to implement it for your own types.
+#[allow(async_fn_in_trait)]
+pub trait PowerControl<DELAY>: sealed::Sealed
+where
+    DELAY: delay::DelayNs,
+{
+    type Error;
+
+    async fn disable(&mut self, delay: &mut DELAY) -> Result<(), Self::Error>;
+    async fn enable(&mut self, delay: &mut DELAY) -> Result<(), Self::Error>;
+}
+
+impl<DELAY> PowerControl<DELAY> for ()
+where
+    DELAY: delay::DelayNs,
+{
+    type Error = core::convert::Infallible;
+
+    // To power down the chip the PD_SCK line has to be held in a 'high' state for more than
+    // 60µs. With no PD_SCK pin wired up there is nothing to hold high, so this is not
+    // implemented. Use `Hx711::new_with_power_pin` for a driver where it is.
+    async fn disable(&mut self, _delay: &mut DELAY) -> Result<(), Self::Error> {
+        unimplemented!("power_down needs a PD_SCK pin, see Hx711::new_with_power_pin");
+    }
+
+    async fn enable(&mut self, _delay: &mut DELAY) -> Result<(), Self::Error> {
+        unimplemented!("power_down needs a PD_SCK pin, see Hx711::new_with_power_pin");
+    }
+}
+
+/// Wraps a GPIO output tied to the HX711's PD_SCK line. Produced by
+/// [`Hx711::new_with_power_pin`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerPin<P> {
+    pin: P,
+}
+
+impl<P> sealed::Sealed for PowerPin<P> {}
+
+impl<DELAY, P> PowerControl<DELAY> for PowerPin<P>
+where
+    DELAY: delay::DelayNs,
+    P: OutputPin,
+{
+    type Error = P::Error;
+
+    async fn disable(&mut self, delay: &mut DELAY) -> Result<(), Self::Error> {
+        // when PD_SCK pin changes from low to high and stays at high for longer than 60µs,
+        // HX711 enters power down mode.
+        self.pin.set_high()?;
+        delay.delay_us(60).await;
+        Ok(())
+    }
+
+    async fn enable(&mut self, _delay: &mut DELAY) -> Result<(), Self::Error> {
+        // When PD_SCK returns to low, chip will reset and enter normal operation mode.
+        self.pin.set_low()
+    }
+}
+
+/// Represents an instance of a HX711 device.
+///
+/// `MODE` is a zero-sized marker ([`ChAGain128`], [`ChBGain32`] or [`ChAGain64`]) tracking at
+/// compile time which channel/gain the device is currently configured to convert. Switch modes
+/// with the `into_*` methods, which consume `self` and hand back a `Hx711` retagged with the new
+/// mode, so `read_val` can only ever be called on a driver known, at compile time, to be in the
+/// mode you expect. The `i32` a read returns is plain and untagged once it leaves the driver, so
+/// this doesn't prevent mixing up readings you've already stored or passed elsewhere.
+///
+/// `EOC` is the [`DataReady`] strategy used to detect a finished conversion: `()` (the default,
+/// see [`Hx711::new`]) polls DOUT over SPI, while [`DataReadyPin`] (see
+/// [`Hx711::new_with_data_ready`]) watches a dedicated GPIO pin instead.
+///
+/// `PWR` is the [`PowerControl`] strategy used by [`disable`](Self::disable)/
+/// [`enable`](Self::enable) to actually power the chip down/up: `()` (the default) has no pin
+/// wired up, so those stay unimplemented, while [`PowerPin`] (see
+/// [`Hx711::new_with_power_pin`]) drives a real GPIO output tied to PD_SCK.
+///
+/// `EOC` and `PWR` are independent: [`with_data_ready`](Self::with_data_ready) and
+/// [`with_power_pin`](Self::with_power_pin) each add one of them to a driver that doesn't have
+/// it yet, so a single driver can have both a data-ready pin and a PD_SCK pin.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Hx711<SPI, DELAY, MODE = ChAGain128, EOC = (), PWR = ()> {
     spi: SPI,
     delay: DELAY,
-    // device specific
+    // device specific, only tracked at runtime when the `runtime-mode` fallback is enabled
+    #[cfg(feature = "runtime-mode")]
     mode: Mode,
+    _mode: PhantomData<MODE>,
+    eoc: EOC,
+    pd_sck: PWR,
 }
 // //  needed to satisfy the trait bound in scales
 // impl<SPI> Read<i32, nb::Error<E>> for Hx711<SPI>
@@ -92,63 +359,205 @@ pub struct Hx711<SPI, DELAY> {
 //     }
 // }
 
-impl<SPI, DELAY> Hx711<SPI, DELAY>
+impl<SPI, DELAY> Hx711<SPI, DELAY, ChAGain128, (), ()>
 where
     DELAY: delay::DelayNs,
     SPI: spi::SpiBus,
-    SPI::Error: defmt::Format,
 {
     /// opens a connection to a HX711 on a specified SPI.
     ///
     /// The datasheet specifies PD_SCK high time and PD_SCK low time to be in the 0.2 to 50 us range,
     /// therefore bus speed has to be between 5 MHz and 20 kHz. 1 MHz seems to be a good choice.
     /// D is an embedded_hal implementation of DelayMs.
+    ///
+    /// The returned driver is tagged with [`ChAGain128`], the mode the chip powers up in, and
+    /// detects a finished conversion by polling DOUT over SPI. Use
+    /// [`new_with_data_ready`](Self::new_with_data_ready) to watch a dedicated GPIO pin instead,
+    /// and/or [`new_with_power_pin`](Self::new_with_power_pin) for real `disable`/`enable`.
     pub fn new(spi: SPI, delay: DELAY) -> Self {
         Hx711 {
             spi,
             delay,
+            #[cfg(feature = "runtime-mode")]
             mode: Mode::ChAGain128,
+            _mode: PhantomData,
+            eoc: (),
+            pd_sck: (),
         }
     }
+}
 
+impl<SPI, DELAY, P> Hx711<SPI, DELAY, ChAGain128, DataReadyPin<P>, ()>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+    P: InputPin,
+{
+    /// Opens a connection to a HX711 on a specified SPI, additionally wired up with `dout`, a
+    /// GPIO input on the HX711's DOUT/EOC line. A finished conversion is then detected by
+    /// polling `dout` directly instead of spending a SPI transaction on every poll.
+    ///
+    /// `timeout_attempts` bounds how many `DATA_READY_BACKOFF_US`-spaced polls of `dout` are
+    /// made before a read gives up with [`Error::NotReadyInTime`].
+    pub fn new_with_data_ready(spi: SPI, delay: DELAY, dout: P, timeout_attempts: u16) -> Self {
+        Hx711 {
+            spi,
+            delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChAGain128,
+            _mode: PhantomData,
+            eoc: DataReadyPin {
+                pin: dout,
+                timeout_attempts,
+            },
+            pd_sck: (),
+        }
+    }
+}
+
+impl<SPI, DELAY, P> Hx711<SPI, DELAY, ChAGain128, (), PowerPin<P>>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+    P: OutputPin,
+{
+    /// Opens a connection to a HX711 on a specified SPI, additionally wired up with `pd_sck`, a
+    /// GPIO output tied to the HX711's PD_SCK line. This lets [`disable`](Hx711::disable) and
+    /// [`enable`](Hx711::enable) actually power the chip down/up instead of being unimplemented.
+    pub fn new_with_power_pin(spi: SPI, delay: DELAY, pd_sck: P) -> Self {
+        Hx711 {
+            spi,
+            delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChAGain128,
+            _mode: PhantomData,
+            eoc: (),
+            pd_sck: PowerPin { pin: pd_sck },
+        }
+    }
+}
+
+impl<SPI, DELAY, MODE, PWR> Hx711<SPI, DELAY, MODE, (), PWR>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+{
+    /// Adds a dedicated EOC/DOUT [`InputPin`] to a driver that doesn't have one yet, consuming
+    /// `self` and handing back a driver that detects a finished conversion by polling `dout`
+    /// directly instead of spending a SPI transaction on every poll. Composes with
+    /// [`new_with_power_pin`](Self::new_with_power_pin)/[`with_power_pin`](Self::with_power_pin),
+    /// so a driver can have both a data-ready pin and a PD_SCK pin.
+    ///
+    /// `timeout_attempts` bounds how many `DATA_READY_BACKOFF_US`-spaced polls of `dout` are
+    /// made before a read gives up with [`Error::NotReadyInTime`].
+    pub fn with_data_ready<P>(
+        self,
+        dout: P,
+        timeout_attempts: u16,
+    ) -> Hx711<SPI, DELAY, MODE, DataReadyPin<P>, PWR>
+    where
+        P: InputPin,
+    {
+        Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: self.mode,
+            _mode: self._mode,
+            eoc: DataReadyPin {
+                pin: dout,
+                timeout_attempts,
+            },
+            pd_sck: self.pd_sck,
+        }
+    }
+}
+
+impl<SPI, DELAY, MODE, EOC> Hx711<SPI, DELAY, MODE, EOC, ()>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+{
+    /// Adds a GPIO output tied to the HX711's PD_SCK line to a driver that doesn't have one yet,
+    /// consuming `self` and handing back a driver where [`disable`](Hx711::disable) and
+    /// [`enable`](Hx711::enable) actually power the chip down/up instead of being unimplemented.
+    /// Composes with
+    /// [`new_with_data_ready`](Self::new_with_data_ready)/[`with_data_ready`](Self::with_data_ready),
+    /// so a driver can have both a PD_SCK pin and a data-ready pin.
+    pub fn with_power_pin<P>(self, pd_sck: P) -> Hx711<SPI, DELAY, MODE, EOC, PowerPin<P>>
+    where
+        P: OutputPin,
+    {
+        Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: self.mode,
+            _mode: self._mode,
+            eoc: self.eoc,
+            pd_sck: PowerPin { pin: pd_sck },
+        }
+    }
+}
+
+impl<SPI, DELAY, MODE, EOC, PWR> Hx711<SPI, DELAY, MODE, EOC, PWR>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+    MODE: ModeBits,
+    EOC: DataReady<SPI, DELAY>,
+{
     /// reads a value from the HX711 and returns it
     /// # Errors
     /// Returns SPI errors and nb::Error::WouldBlock if data isn't ready to be read from hx711
     pub async fn read_val(&mut self) -> Result<i32, Error<SPI::Error>> {
-        // check if data is ready
-        // When output data is not ready for retrieval, digital output pin DOUT is high.
-        // Serial clock input PD_SCK should be low. When DOUT goes
-        // to low, it indicates data is ready for retrieval.
-        let mut txrx: [u8; 1] = [SIGNAL_LOW];
+        self.eoc.wait_ready(&mut self.spi, &mut self.delay).await?;
 
-        self.spi.transfer_in_place(&mut txrx).await?;
+        #[cfg(feature = "runtime-mode")]
+        let next_mode_bits = self.mode as u8;
+        #[cfg(not(feature = "runtime-mode"))]
+        let next_mode_bits = MODE::BITS;
 
-        let mut attempt = 0;
-        loop {
-            if txrx[0] & 0b01 != 0b01 {
-                break;
-            }
+        clock_out_and_select_next_mode(&mut self.spi, next_mode_bits).await
+    }
 
-            // as long as the lowest bit is high there is no data waiting
-            if attempt > 1000 {
-                return Err(Error::NotReadyInTime);
-            }
+    /// Reads `n` consecutive samples (see [`MAX_FILTER_SAMPLES`] for the cap on `n`) and
+    /// returns their arithmetic mean, to even out noise on a load-cell reading.
+    /// # Errors
+    /// Returns SPI errors and [`Error::NotReadyInTime`], same as [`read_val`](Self::read_val).
+    pub async fn read_averaged(&mut self, n: u16) -> Result<i32, Error<SPI::Error>> {
+        let n = clamp_sample_count(n);
 
-            attempt += 1;
+        let mut samples = [0i32; MAX_FILTER_SAMPLES];
+        for sample in samples.iter_mut().take(n) {
+            *sample = self.read_val().await?;
         }
 
-        let mut buffer: [u8; 7] = [CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, self.mode as u8];
+        Ok(average(&samples[..n]))
+    }
+
+    /// Reads `n` consecutive samples (see [`MAX_FILTER_SAMPLES`] for the cap on `n`) and
+    /// returns their median, to reject the occasional outlier spike common on load-cell setups
+    /// without the smoothing-out-real-change behavior of [`read_averaged`](Self::read_averaged).
+    /// # Errors
+    /// Returns SPI errors and [`Error::NotReadyInTime`], same as [`read_val`](Self::read_val).
+    pub async fn read_median(&mut self, n: u16) -> Result<i32, Error<SPI::Error>> {
+        let n = clamp_sample_count(n);
 
-        self.spi.transfer_in_place(&mut buffer).await?;
+        let mut samples = [0i32; MAX_FILTER_SAMPLES];
+        for sample in samples.iter_mut().take(n) {
+            *sample = self.read_val().await?;
+        }
 
-        Ok(decode_output(&buffer)) // value should be in range 0x800000 - 0x7fffff according to datasheet
+        Ok(median(&mut samples[..n]))
     }
 
-    /// Reset the chip to it's default state. Mode is set to convert channel A with a gain factor of 128.
+    /// Reset the chip to it's default state and hand back a driver tagged with [`ChAGain128`],
+    /// the mode the chip resets into.
     /// # Errors
     /// Returns SPI errors
     #[inline]
-    pub async fn reset(&mut self) -> Result<(), SPI::Error> {
+    pub async fn reset(mut self) -> Result<Hx711<SPI, DELAY, ChAGain128, EOC, PWR>, SPI::Error> {
         // when PD_SCK pin changes from low to high and stays at high for longer than 60µs,
         // HX711 enters power down mode.
         // When PD_SCK returns to low, chip will reset and enter normal operation mode.
@@ -160,14 +569,85 @@ where
         let mut buffer: [u8; 301] = RESET_SIGNAL;
 
         self.spi.transfer_in_place(&mut buffer).await?;
-        self.mode = Mode::ChAGain128; // this is the default mode after reset
 
-        Ok(())
+        Ok(Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChAGain128, // this is the default mode after reset
+            _mode: PhantomData,
+            eoc: self.eoc,
+            pd_sck: self.pd_sck,
+        })
+    }
+
+    /// Switch to channel A, gain 128. Issues the extra read the HX711 needs to latch the mode
+    /// for the next conversion, consuming `self` and handing back a driver tagged accordingly.
+    /// # Errors
+    /// Returns SPI errors
+    pub async fn into_cha_gain128(
+        mut self,
+    ) -> Result<Hx711<SPI, DELAY, ChAGain128, EOC, PWR>, Error<SPI::Error>> {
+        self.eoc.wait_ready(&mut self.spi, &mut self.delay).await?;
+        clock_out_and_select_next_mode(&mut self.spi, ChAGain128::BITS).await?;
+        Ok(Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChAGain128,
+            _mode: PhantomData,
+            eoc: self.eoc,
+            pd_sck: self.pd_sck,
+        })
+    }
+
+    /// Switch to channel A, gain 64. Issues the extra read the HX711 needs to latch the mode
+    /// for the next conversion, consuming `self` and handing back a driver tagged accordingly.
+    /// # Errors
+    /// Returns SPI errors
+    pub async fn into_cha_gain64(
+        mut self,
+    ) -> Result<Hx711<SPI, DELAY, ChAGain64, EOC, PWR>, Error<SPI::Error>> {
+        self.eoc.wait_ready(&mut self.spi, &mut self.delay).await?;
+        clock_out_and_select_next_mode(&mut self.spi, ChAGain64::BITS).await?;
+        Ok(Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChAGain64,
+            _mode: PhantomData,
+            eoc: self.eoc,
+            pd_sck: self.pd_sck,
+        })
+    }
+
+    /// Switch to channel B, gain 32. Issues the extra read the HX711 needs to latch the mode
+    /// for the next conversion, consuming `self` and handing back a driver tagged accordingly.
+    /// # Errors
+    /// Returns SPI errors
+    pub async fn into_chb_gain32(
+        mut self,
+    ) -> Result<Hx711<SPI, DELAY, ChBGain32, EOC, PWR>, Error<SPI::Error>> {
+        self.eoc.wait_ready(&mut self.spi, &mut self.delay).await?;
+        clock_out_and_select_next_mode(&mut self.spi, ChBGain32::BITS).await?;
+        Ok(Hx711 {
+            spi: self.spi,
+            delay: self.delay,
+            #[cfg(feature = "runtime-mode")]
+            mode: Mode::ChBGain32,
+            _mode: PhantomData,
+            eoc: self.eoc,
+            pd_sck: self.pd_sck,
+        })
     }
 
     /// Set the mode to the value specified.
+    ///
+    /// This is the `runtime-mode` fallback: it changes behavior without changing `Self`'s type,
+    /// so prefer the `into_*` conversions unless you need to pick the mode at runtime.
     /// # Errors
     /// Returns SPI errors
+    #[cfg(feature = "runtime-mode")]
     #[inline]
     pub async fn set_mode(&mut self, m: Mode) -> Result<Mode, Error<SPI::Error>> {
         self.mode = m;
@@ -177,41 +657,64 @@ where
         Ok(m)
     }
 
+    #[cfg(feature = "runtime-mode")]
     #[inline]
     /// Get the current mode.
     pub fn mode(&mut self) -> Mode {
         self.mode
     }
 
+    #[cfg(feature = "runtime-mode")]
     #[inline]
     /// This is for compatibility only. Use [mode]() instead.
     pub fn get_mode(&mut self) -> Mode {
         self.mode
     }
+}
 
-    /// To power down the chip the PD_SCK line has to be held in a 'high' state. To do this we
-    /// would need to write a constant stream of binary '1' to the SPI bus which would totally defy
-    /// the purpose. Therefore it's not implemented.
-    // If the SDO pin would be idle high (and at least some MCU's seem to do that in mode 1) then the chip would automatically
-    // power down if not used. Cool!
-    pub fn disable(&mut self) -> Result<(), SPI::Error> {
-        // when PD_SCK pin changes from low to high and stays at high for longer than 60µs, HX711 enters power down mode
-        // When PD_SCK returns to low, chip will reset and enter normal operation mode.
-        // this can't be implemented with SPI because we would have to write a constant stream
-        // of binary '1' which would block the process
-        unimplemented!("power_down is not possible with this driver implementation");
+impl<SPI, DELAY, MODE, EOC, PWR> Hx711<SPI, DELAY, MODE, EOC, PWR>
+where
+    DELAY: delay::DelayNs,
+    SPI: spi::SpiBus,
+    PWR: PowerControl<DELAY>,
+{
+    /// Powers the HX711 down. With a PD_SCK pin wired up (see
+    /// [`new_with_power_pin`](Hx711::new_with_power_pin)) this drives it high and holds it there
+    /// for the 60µs the datasheet requires to enter power-down mode; without one, this is not
+    /// implemented.
+    /// # Errors
+    /// Returns errors from the PD_SCK pin
+    pub async fn disable(&mut self) -> Result<(), PWR::Error> {
+        self.pd_sck.disable(&mut self.delay).await
     }
 
-    /// Power up / down is not implemented (see disable)
-    pub fn enable(&mut self) -> Result<(), SPI::Error> {
-        // when PD_SCK pin changes from low to high and stays at high for longer than 60µs, HX711 enters power down mode
-        // When PD_SCK returns to low, chip will reset and enter normal operation mode.
-        // this can't be implemented with SPI because we would have to write a constant stream
-        // of binary '1' which would block the process
-        unimplemented!("power_down is not possible with this driver implementation");
+    /// Wakes the HX711 back up. With a PD_SCK pin wired up this drives it low, resuming normal
+    /// operation; without one, this is not implemented (see [`disable`](Self::disable)).
+    /// # Errors
+    /// Returns errors from the PD_SCK pin
+    pub async fn enable(&mut self) -> Result<(), PWR::Error> {
+        self.pd_sck.enable(&mut self.delay).await
     }
 }
 
+/// Clocks out the 24-bit result of a ready conversion and, on the last (doubled) bit pair,
+/// clocks out `next_mode_bits` to select the mode of the *next* conversion. Shared by
+/// [`Hx711::read_val`] and the `into_*` mode conversions, which only differ in which bits they
+/// select next; waiting for the conversion to be ready is handled separately by [`DataReady`].
+async fn clock_out_and_select_next_mode<SPI>(
+    spi: &mut SPI,
+    next_mode_bits: u8,
+) -> Result<i32, Error<SPI::Error>>
+where
+    SPI: spi::SpiBus,
+{
+    let mut buffer: [u8; 7] = [CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, next_mode_bits];
+
+    spi.transfer_in_place(&mut buffer).await?;
+
+    Ok(decode_output(&buffer)) // value should be in range 0x800000 - 0x7fffff according to datasheet
+}
+
 #[bitmatch]
 fn decode_output(buffer: &[u8; 7]) -> i32 {
     // buffer contains the 2's complement of the reading with every bit doubled
@@ -245,8 +748,6 @@ fn decode_output(buffer: &[u8; 7]) -> i32 {
 mod tests {
     use super::*;
     use test_case::test_case;
-    // embedded_hal implementation
-    use embedded_hal_mock::spi::{Mock as Spi, Transaction as SpiTransaction};
 
     #[test_case(&[0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55] => 0; "alternating convert to zeros")]
     #[test_case(&[0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA] => -1; "alternating convert to ones")]
@@ -257,22 +758,29 @@ mod tests {
         decode_output(&buffer)
     }
 
-    #[test]
-    fn test_read() {
-        // Data the mocked up SPI bus should return
-        let expectations = [
-            SpiTransaction::transfer(vec![SIGNAL_LOW], vec![SIGNAL_LOW]),
-            SpiTransaction::transfer(
-                vec![CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, CLOCK, GAIN128],
-                vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, SIGNAL_LOW],
-            ),
-        ];
-
-        let spi = Spi::new(&expectations);
-        let mut hx711 = Hx711::new(spi);
-
-        //hx711.reset()?;
-        let v = block!(hx711.read())?;
-        assert_eq!(v, 0);
+    #[test_case(0 => 1; "0 is rounded up to 1 sample")]
+    #[test_case(1 => 1; "1 is unchanged")]
+    #[test_case(MAX_FILTER_SAMPLES as u16 => MAX_FILTER_SAMPLES; "MAX_FILTER_SAMPLES is unchanged")]
+    #[test_case(MAX_FILTER_SAMPLES as u16 + 1 => MAX_FILTER_SAMPLES; "just over MAX_FILTER_SAMPLES is capped")]
+    #[test_case(u16::MAX => MAX_FILTER_SAMPLES; "u16::MAX is capped")]
+    fn test_clamp_sample_count(n: u16) -> usize {
+        clamp_sample_count(n)
+    }
+
+    #[test_case(&[0] => 0; "single sample")]
+    #[test_case(&[1, 2, 3] => 2; "exact mean")]
+    #[test_case(&[1, 2] => 1; "mean truncates towards zero")]
+    #[test_case(&[i32::MAX, i32::MAX] => i32::MAX; "sum doesn't overflow i32")]
+    #[test_case(&[-10, 10] => 0; "negative and positive samples")]
+    fn test_average(samples: &[i32]) -> i32 {
+        average(samples)
+    }
+
+    #[test_case(&mut [5] => 5; "single sample")]
+    #[test_case(&mut [3, 1, 2] => 2; "odd count picks the middle after sorting")]
+    #[test_case(&mut [4, 1, 3, 2] => 3; "even count picks the upper middle after sorting")]
+    #[test_case(&mut [5, -100, 4, 6] => 5; "outlier doesn't skew the result")]
+    fn test_median(samples: &mut [i32]) -> i32 {
+        median(samples)
     }
 }